@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use crate::{Level, Log};
+
+/// Accumulates frequency counts over a stream of parsed [`Log`]s.
+///
+/// Feed logs in with [`Aggregator::observe`] and pull a snapshot out with
+/// [`Aggregator::report`], or query the busiest values for a single `data` key
+/// with [`Aggregator::top`] (e.g. the most frequent `user` or `postal_code`).
+#[derive(Debug, Default)]
+pub struct Aggregator {
+    by_level: HashMap<Level, usize>,
+    by_name: HashMap<String, usize>,
+    keys: HashMap<String, usize>,
+    values: HashMap<String, HashMap<String, usize>>,
+}
+
+impl Aggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn observe(&mut self, log: &Log) {
+        if let Some(level) = log.level_parsed() {
+            *self.by_level.entry(level).or_insert(0) += 1;
+        }
+        if let Some(name) = &log.name {
+            *self.by_name.entry(name.clone()).or_insert(0) += 1;
+        }
+        for (key, value) in &log.data {
+            *self.keys.entry(key.clone()).or_insert(0) += 1;
+            *self.values.entry(key.clone()).or_default().entry(value.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    /// The `n` most frequent values seen for `data[key]`, most frequent first.
+    pub fn top(&self, key: &str, n: usize) -> Vec<(&str, usize)> {
+        let mut values: Vec<(&str, usize)> = self.values.get(key)
+            .map(|counts| counts.iter().map(|(v, c)| (v.as_str(), *c)).collect())
+            .unwrap_or_default();
+        values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        values.truncate(n);
+        values
+    }
+
+    pub fn report(&self) -> Summary {
+        Summary {
+            by_level: sorted_by_count(self.by_level.iter().map(|(l, c)| (*l, *c))),
+            by_name: sorted_by_count(self.by_name.iter().map(|(n, c)| (n.clone(), *c))),
+            keys: sorted_by_count(self.keys.iter().map(|(k, c)| (k.clone(), *c))),
+        }
+    }
+}
+
+fn sorted_by_count<T: Ord>(iter: impl Iterator<Item=(T, usize)>) -> Vec<(T, usize)> {
+    let mut v: Vec<_> = iter.collect();
+    v.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    v
+}
+
+/// A point-in-time snapshot from [`Aggregator::report`], sorted most-frequent-first.
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub by_level: Vec<(Level, usize)>,
+    pub by_name: Vec<(String, usize)>,
+    pub keys: Vec<(String, usize)>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{InnerStrategy, Parser, Strategy};
+
+    fn log(line: &str) -> Log {
+        Parser::new(Strategy::Direct(InnerStrategy::Logfmt)).parse(line.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_observe_and_report() {
+        let mut agg = Aggregator::new();
+        agg.observe(&log("INFO server.a: ok user=7"));
+        agg.observe(&log("ERROR server.a: boom user=7"));
+        agg.observe(&log("ERROR server.b: boom user=8"));
+        let summary = agg.report();
+        assert_eq!(summary.by_level, vec![(Level::Error, 2), (Level::Info, 1)]);
+        assert_eq!(summary.by_name, vec![("server.a".to_string(), 2), ("server.b".to_string(), 1)]);
+        assert_eq!(summary.keys, vec![("user".to_string(), 3)]);
+    }
+
+    #[test]
+    fn test_top_values_for_key() {
+        let mut agg = Aggregator::new();
+        agg.observe(&log("INFO server.a: ok user=7"));
+        agg.observe(&log("INFO server.a: ok user=7"));
+        agg.observe(&log("INFO server.a: ok user=8"));
+        assert_eq!(agg.top("user", 1), vec![("7", 2)]);
+        assert_eq!(agg.top("missing", 5), Vec::<(&str, usize)>::new());
+    }
+}
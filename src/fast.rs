@@ -9,11 +9,21 @@ impl Range {
     pub fn end(&self) -> usize {
         self.end.into()
     }
+
+    /// Builds a range over `[start, end)`. Returns `None` for an empty span, since
+    /// `end: NonZeroUsize` can't represent `end == 0`; callers that need to express
+    /// an empty value should store that `None` rather than a zero-length `Range`.
+    fn new(start: usize, end: usize) -> Option<Range> {
+        if start == end {
+            return None;
+        }
+        NonZeroUsize::new(end).map(|end| Range { start, end })
+    }
 }
 
 pub struct Log {
     line: String,
-    pairs: Vec<(Range, Range)>,
+    pairs: Vec<(Option<Range>, Option<Range>)>,
     level: Option<Range>,
     path: Option<Range>,
     message: Option<Range>,
@@ -47,21 +57,184 @@ impl Log {
 
     pub fn pairs(&self) -> Vec<(&str, &str)> {
         self.pairs.iter().map(|(key, value)| {
-            let key = &self.line[key.start..key.end()];
-            let value = &self.line[value.start..value.end()];
+            let key = key.as_ref().map(|k| &self.line[k.start..k.end()]).unwrap_or("");
+            let value = value.as_ref().map(|v| &self.line[v.start..v.end()]).unwrap_or("");
             (key, value)
         }).collect()
     }
 }
 
+/// Zero-copy counterpart to [`crate::logfmt::parse_logfmt`]: performs the same
+/// tokenization (level keyword, dotted/colon logger name, `key=value` pairs with
+/// `"quoted \"values\""`) but records byte offsets into `line` instead of calling
+/// `.to_string()`/`HashMap::insert`, so parsing a line allocates nothing beyond the
+/// owned `line` and the `pairs` vector.
+pub fn parse_logfmt(line: String) -> Log {
+    let mut level = None;
+    let mut path = None;
+    let mut pairs = Vec::new();
+    let mut log_message_start = 0usize; // markers for simplified message, that ignores the metadata
+    let mut log_message_end = None;
+    {
+        let s = line.as_str();
+        let mut chars = s.char_indices().peekable();
+        let mut cur_token_idx = 0;
+        while let Some(&(i, c)) = chars.peek() {
+            if c == '=' {
+                break;
+            }
+            let (i, c) = chars.next().unwrap();
+            let at_colon = c == ':' && matches!(chars.peek(), Some((_, ' ')));
+            if c == ' ' || at_colon {
+                match &s[cur_token_idx..i] {
+                    "INFO" | "WARN" | "WARNING" | "ERROR" | "DEBUG" | "TRACE" | "LOG" => {
+                        if level.is_none() {
+                            level = Range::new(cur_token_idx, i);
+                        }
+                        if cur_token_idx == log_message_start {
+                            log_message_start = i + 1;
+                        }
+                    }
+                    // if cur_token_idx != log_message_start, then we're already "in the message"
+                    // and we should continue rather than assuming this is the logger name
+                    token if token.contains(['.', ':']) && cur_token_idx == log_message_start => {
+                        log_message_start = i + 1;
+                        if path.is_none() {
+                            path = Range::new(cur_token_idx, i);
+                        }
+                    }
+                    _ => {}
+                }
+                cur_token_idx = i + 1;
+            }
+            if at_colon {
+                if log_message_start > 0 {
+                    log_message_start += 1;
+                }
+                break;
+            }
+        }
+        // assume we're in message and/or key value section now.
+        while let Some((i, c)) = chars.next() {
+            match c {
+                ' ' => {
+                    log_message_end = None;
+                    cur_token_idx = i + 1;
+                }
+                '=' => {
+                    if log_message_end.is_none() && cur_token_idx > 0 {
+                        log_message_end = Some(cur_token_idx - 1);
+                    }
+                    let key_start = cur_token_idx;
+                    let key_end = i;
+                    cur_token_idx = i + 1;
+                    let mut end_token_idx = s.len();
+                    'outer: while let Some((i, ch)) = chars.next() {
+                        match ch {
+                            ' ' => {
+                                end_token_idx = i;
+                                break 'outer;
+                            }
+                            '"' => {
+                                cur_token_idx = i + 1;
+                                while let Some((i, ch)) = chars.next() {
+                                    match ch {
+                                        '\\' => {
+                                            chars.next();
+                                            continue;
+                                        }
+                                        '"' => {
+                                            end_token_idx = i;
+                                            break 'outer;
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    // A key or value can be empty (e.g. a line starting with `=foo`), which
+                    // `Range::new` can't represent since `end: NonZeroUsize` forbids `end == 0`.
+                    // Push the pair regardless, with `None` standing in for that empty span,
+                    // so an empty key at offset 0 no longer drops its value with it.
+                    let key = Range::new(key_start, key_end);
+                    let value = Range::new(cur_token_idx, end_token_idx);
+                    pairs.push((key, value));
+                    cur_token_idx = end_token_idx + 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    let message_end = log_message_end.unwrap_or(line.len());
+    // The header loop's and key=value loop's token-index bookkeeping can disagree on
+    // short inputs (e.g. a second dotted/colon token immediately followed by a short
+    // `key=value` pair), leaving `message_end` behind `log_message_start`. Treat that
+    // as an empty message rather than constructing an inverted `Range` that would
+    // panic when sliced.
+    let message = (message_end >= log_message_start)
+        .then(|| Range::new(log_message_start, message_end))
+        .flatten();
+    Log {
+        line,
+        pairs,
+        level,
+        path,
+        message,
+    }
+}
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::collections::HashMap;
     use std::mem::size_of;
 
     #[test]
     fn test_size() {
         assert_eq!(size_of::<Range>(), size_of::<Option<Range>>());
     }
+
+    #[test]
+    fn test_parse_logfmt_dotted_tokens_then_short_pair_does_not_panic() {
+        for line in ["x:y z=1", "a:b key=v", "req:1 resp:2 code=500"] {
+            let log = parse_logfmt(line.to_string());
+            let _ = log.message();
+        }
+    }
+
+    #[test]
+    fn test_parse_logfmt() {
+        let line = "INFO server::onboarding::location_availability: Updated profile with postal code tz=America/Chicago area=- postal_code=10001 req=00djxys6h3gzskbwhwy5zk_pgkx user=7";
+        let log = parse_logfmt(line.to_string());
+        assert_eq!(log.level(), Some("INFO"));
+        assert_eq!(log.path(), Some("server::onboarding::location_availability"));
+        assert_eq!(log.message(), Some("Updated profile with postal code"));
+        let pairs: HashMap<_, _> = log.pairs().into_iter().collect();
+        assert_eq!(pairs["tz"], "America/Chicago");
+        assert_eq!(pairs["area"], "-");
+        assert_eq!(pairs["postal_code"], "10001");
+        assert_eq!(pairs["req"], "00djxys6h3gzskbwhwy5zk_pgkx");
+        assert_eq!(pairs["user"], "7");
+    }
+
+    #[test]
+    fn test_parse_logfmt_empty_key_at_offset_zero() {
+        let line = "=foo bar=baz";
+        let log = parse_logfmt(line.to_string());
+        let pairs: HashMap<_, _> = log.pairs().into_iter().collect();
+        assert_eq!(pairs[""], "foo");
+        assert_eq!(pairs["bar"], "baz");
+    }
+
+    #[test]
+    fn test_parse_logfmt_quoted_and_empty_value() {
+        let line = r#"msg="hello world" empty="" tag=ok"#;
+        let log = parse_logfmt(line.to_string());
+        let pairs: HashMap<_, _> = log.pairs().into_iter().collect();
+        assert_eq!(pairs["msg"], "hello world");
+        assert_eq!(pairs["empty"], "");
+        assert_eq!(pairs["tag"], "ok");
+    }
 }
@@ -0,0 +1,127 @@
+use regex::Regex;
+use crate::{Level, Log};
+
+/// Decides whether a parsed [`Log`] should be kept.
+///
+/// Every configured criterion must match for [`Filter::matches`] to return `true`;
+/// criteria that were never set are ignored. Build one with [`Filter::new`] and the
+/// chained setters below.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    min_level: Option<Level>,
+    allow_names: Option<Vec<String>>,
+    deny_names: Option<Vec<String>>,
+    data: Vec<(String, String)>,
+    message: Option<Regex>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop logs below `level` (or with no recognized level at all).
+    pub fn min_level(mut self, level: Level) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    /// Keep only logs whose `name` is in this allow list.
+    pub fn allow_name(mut self, name: impl Into<String>) -> Self {
+        self.allow_names.get_or_insert_with(Vec::new).push(name.into());
+        self
+    }
+
+    /// Drop logs whose `name` is in this deny list.
+    pub fn deny_name(mut self, name: impl Into<String>) -> Self {
+        self.deny_names.get_or_insert_with(Vec::new).push(name.into());
+        self
+    }
+
+    /// Require `log.data[key]` to be present and equal to `value`, compared via
+    /// `DataValue`'s `Display` form so `postal_code=10001` matches whether the
+    /// value parsed as an `I64` or a `String`.
+    pub fn require(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.data.push((key.into(), value.into()));
+        self
+    }
+
+    /// Require `log.message` to match `regex`.
+    pub fn message_regex(mut self, regex: Regex) -> Self {
+        self.message = Some(regex);
+        self
+    }
+
+    pub fn matches(&self, log: &Log) -> bool {
+        if let Some(min) = self.min_level {
+            match log.level_parsed() {
+                Some(level) if level >= min => {}
+                _ => return false,
+            }
+        }
+        if let Some(allow) = &self.allow_names {
+            match &log.name {
+                Some(name) if allow.iter().any(|n| n == name) => {}
+                _ => return false,
+            }
+        }
+        if let Some(deny) = &self.deny_names {
+            if let Some(name) = &log.name {
+                if deny.iter().any(|n| n == name) {
+                    return false;
+                }
+            }
+        }
+        for (key, value) in &self.data {
+            match log.data.get(key) {
+                Some(v) if &v.to_string() == value => {}
+                _ => return false,
+            }
+        }
+        if let Some(re) = &self.message {
+            if !re.is_match(&log.message) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{InnerStrategy, Parser, Strategy};
+
+    fn log(line: &str) -> Log {
+        Parser::new(Strategy::Direct(InnerStrategy::Logfmt)).parse(line.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_min_level() {
+        let log = log("WARN server.x: disk low free=2");
+        assert!(Filter::new().min_level(Level::Warn).matches(&log));
+        assert!(!Filter::new().min_level(Level::Error).matches(&log));
+    }
+
+    #[test]
+    fn test_name_allow_deny() {
+        let log = log("INFO server.onboarding: updated profile user=7");
+        assert!(Filter::new().allow_name("server.onboarding").matches(&log));
+        assert!(!Filter::new().allow_name("server.billing").matches(&log));
+        assert!(!Filter::new().deny_name("server.onboarding").matches(&log));
+    }
+
+    #[test]
+    fn test_data_predicate_matches_display_form() {
+        let log = log("INFO server.x: updated profile postal_code=10001 user=7");
+        assert!(Filter::new().require("postal_code", "10001").matches(&log));
+        assert!(!Filter::new().require("postal_code", "76133").matches(&log));
+    }
+
+    #[test]
+    fn test_message_regex() {
+        let log = log("INFO server.x: updated profile postal_code=10001");
+        assert!(Filter::new().message_regex(Regex::new("updated profile").unwrap()).matches(&log));
+        assert!(!Filter::new().message_regex(Regex::new("deleted").unwrap()).matches(&log));
+    }
+}
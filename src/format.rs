@@ -0,0 +1,111 @@
+use std::io;
+use anyhow::Result;
+use crate::Log;
+
+/// Re-serializes a parsed [`Log`] to some wire or display format.
+///
+/// Each output format is its own implementor behind this one abstraction, so a
+/// pipeline can parse one format and re-emit another (e.g. parse nested
+/// JSON-wrapped logfmt, re-emit clean logfmt).
+pub trait Formatter {
+    fn write(&self, log: &Log, w: &mut dyn io::Write) -> Result<()>;
+}
+
+/// Quotes `s` as a logfmt value if it contains a space or a quote.
+fn quote(s: &str) -> String {
+    if s.contains(' ') || s.contains('"') {
+        format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Emits `level=... name=... msg="..."` followed by sorted `data` pairs.
+pub struct LogfmtFormatter;
+
+impl Formatter for LogfmtFormatter {
+    fn write(&self, log: &Log, w: &mut dyn io::Write) -> Result<()> {
+        if let Some(level) = &log.level {
+            write!(w, "level={} ", quote(level))?;
+        }
+        if let Some(name) = &log.name {
+            write!(w, "name={} ", quote(name))?;
+        }
+        write!(w, "msg={}", quote(&log.message))?;
+        let mut keys: Vec<&String> = log.data.keys().collect();
+        keys.sort();
+        for key in keys {
+            write!(w, " {}={}", key, quote(&log.data[key].to_string()))?;
+        }
+        writeln!(w)?;
+        Ok(())
+    }
+}
+
+/// Serializes the `Log` using its existing serde structure.
+pub struct JsonFormatter;
+
+impl Formatter for JsonFormatter {
+    fn write(&self, log: &Log, w: &mut dyn io::Write) -> Result<()> {
+        serde_json::to_writer(w, log)?;
+        Ok(())
+    }
+}
+
+/// Compact single-line human format: `[name] message key=value ...`.
+pub struct CompactFormatter;
+
+impl Formatter for CompactFormatter {
+    fn write(&self, log: &Log, w: &mut dyn io::Write) -> Result<()> {
+        if let Some(name) = &log.name {
+            write!(w, "[{}] ", name)?;
+        }
+        write!(w, "{}", log.message)?;
+        let mut keys: Vec<&String> = log.data.keys().collect();
+        keys.sort();
+        for key in keys {
+            write!(w, " {}={}", key, log.data[key])?;
+        }
+        writeln!(w)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{InnerStrategy, Parser, Strategy};
+
+    fn log(line: &str) -> Log {
+        Parser::new(Strategy::Direct(InnerStrategy::Logfmt)).parse(line.to_string()).unwrap()
+    }
+
+    fn render(f: &dyn Formatter, log: &Log) -> String {
+        let mut buf = Vec::new();
+        f.write(log, &mut buf).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_logfmt_formatter_quotes_spaces() {
+        let log = log("INFO server.onboarding: updated profile postal_code=10001 area=-");
+        let out = render(&LogfmtFormatter, &log);
+        assert_eq!(out, "level=INFO name=server.onboarding msg=\"updated profile\" area=- postal_code=10001\n");
+    }
+
+    #[test]
+    fn test_json_formatter_round_trips() {
+        let log = log("INFO server.onboarding: updated profile postal_code=10001");
+        let out = render(&JsonFormatter, &log);
+        let parsed: Log = serde_json::from_str(&out).unwrap();
+        assert_eq!(parsed.message, "updated profile");
+        assert_eq!(parsed.data["postal_code"].to_string(), "10001");
+    }
+
+    #[test]
+    fn test_compact_formatter() {
+        let log = log("INFO server.onboarding: updated profile postal_code=10001");
+        let out = render(&CompactFormatter, &log);
+        assert_eq!(out, "[server.onboarding] updated profile postal_code=10001\n");
+    }
+}
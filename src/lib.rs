@@ -1,11 +1,17 @@
 #![allow(unused)]
+pub mod aggregate;
 mod fast;
+pub mod filter;
+pub mod format;
 mod humantime;
 mod json;
 mod logfmt;
+pub mod render;
 
 use std::collections::HashMap;
 use std::fmt::Formatter;
+use std::io::BufRead;
+use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use anyhow::Result;
@@ -24,6 +30,73 @@ pub enum DataValue {
     Duration(std::time::Duration),
 }
 
+/// Normalized log severity, ordered from least to most severe.
+///
+/// `Log.level` keeps the raw casing/spelling the source used (`"info"`,
+/// `"INFO"`, `"WARNING"`); call [`Log::level_parsed`] to get one of these
+/// instead, so severities can be compared and filtered regardless of how
+/// the original line spelled them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Level::Trace => "trace",
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+            Level::Error => "error",
+            Level::Fatal => "fatal",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A log level string didn't match any known spelling or syslog severity (0-7).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseLevelError(String);
+
+impl std::fmt::Display for ParseLevelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unrecognized log level: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseLevelError {}
+
+impl FromStr for Level {
+    type Err = ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let level = match s.trim().to_ascii_lowercase().as_str() {
+            "trace" => Level::Trace,
+            "debug" | "7" => Level::Debug,
+            "info" | "informational" | "notice" | "5" | "6" => Level::Info,
+            "warn" | "warning" | "4" => Level::Warn,
+            "err" | "error" | "3" => Level::Error,
+            "crit" | "critical" | "fatal" | "panic" | "emerg" | "emergency" | "alert" | "0" | "1" | "2" => Level::Fatal,
+            _ => return Err(ParseLevelError(s.to_string())),
+        };
+        Ok(level)
+    }
+}
+
+impl TryFrom<&str> for Level {
+    type Error = ParseLevelError;
+
+    fn try_from(s: &str) -> Result<Level, ParseLevelError> {
+        s.parse()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Log {
     dt: Option<String>,
@@ -38,6 +111,14 @@ pub struct Log {
     data: HashMap<String, DataValue>,
 }
 
+impl Log {
+    /// Normalizes `self.level` (whatever casing/spelling the source used) into a
+    /// typed, orderable [`Level`]. Returns `None` if `level` is unset or unrecognized.
+    pub fn level_parsed(&self) -> Option<Level> {
+        self.level.as_deref()?.parse().ok()
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum InnerStrategy {
     Json,
@@ -92,6 +173,26 @@ impl Parser {
             }
         }
     }
+
+    /// Reads `r` line by line, applying the configured [`Strategy`] to each one, so
+    /// consumers can tail a file or stdin without manually splitting it first.
+    ///
+    /// Blank lines are skipped, a final line lacking a trailing newline is still
+    /// parsed, and a malformed line surfaces as an `Err` item rather than aborting
+    /// the stream, so one bad line doesn't kill a long tail.
+    pub fn parse_reader<R: BufRead>(&self, r: R) -> impl Iterator<Item=Result<Log>> {
+        let strategy = self.strategy;
+        r.lines().filter_map(move |line| {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            if line.trim().is_empty() {
+                return None;
+            }
+            Some(Parser::new(strategy).parse(line))
+        })
+    }
 }
 
 impl std::fmt::Debug for DataValue {
@@ -134,6 +235,32 @@ impl From<&str> for DataValue {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_parse_reader_skips_blank_lines_and_reports_errors_per_line() {
+        let one = r#"{"dt":null,"level":"info","name":null,"message":"one","platform":null,"data":{}}"#;
+        let two = r#"{"dt":null,"level":"info","name":null,"message":"two","platform":null,"data":{}}"#;
+        let input = format!("{one}\n\n  \nnot json\n{two}");
+        let parser = Parser::new(Strategy::Direct(InnerStrategy::Json));
+        let logs: Vec<Result<Log>> = parser.parse_reader(input.as_bytes()).collect();
+        assert_eq!(logs.len(), 3);
+        assert_eq!(logs[0].as_ref().unwrap().message, "one");
+        assert!(logs[1].is_err());
+        assert_eq!(logs[2].as_ref().unwrap().message, "two");
+    }
+
+    #[test]
+    fn test_level_ordering_and_normalization() {
+        assert!(Level::Trace < Level::Debug);
+        assert!(Level::Warn < Level::Error);
+        assert!(Level::Error < Level::Fatal);
+        assert_eq!("warning".parse::<Level>().unwrap(), Level::Warn);
+        assert_eq!("ERR".parse::<Level>().unwrap(), Level::Error);
+        assert_eq!("crit".parse::<Level>().unwrap(), Level::Fatal);
+        assert_eq!("3".parse::<Level>().unwrap(), Level::Error);
+        assert_eq!(Level::Warn.to_string(), "warn");
+        assert!("nonsense".parse::<Level>().is_err());
+    }
+
     #[test]
     fn test_logparse() {
         let json = r#"
@@ -142,6 +269,7 @@ mod test {
         _ = "INFO server::onboarding::location_availability: Updated profile with postal code tz=America/Chicago area=- postal_code=76133 req=00djxys6h3gzskbwhwy5zk_pgkx user=7";
         let log = Parser::nested().parse(json.to_string()).unwrap();
         assert_eq!(log.level, Some("info".to_string()));
+        assert_eq!(log.level_parsed(), Some(Level::Info));
         assert_eq!(log.name, Some("server::onboarding::location_availability".to_string()));
         assert_eq!(log.message, "Updated profile with postal code".to_string());
         assert_eq!(log.data["tz"].to_string(), "America/Chicago");
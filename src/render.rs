@@ -0,0 +1,116 @@
+use std::io::{self, IsTerminal, Write};
+use crate::{Level, Log};
+
+/// Renders a parsed [`Log`] back to a human-readable line, colorized by severity.
+///
+/// Layout is `dt LEVEL name: message key=value ...`, with `data` pairs sorted by
+/// key for stable output.
+pub struct Renderer {
+    color: bool,
+}
+
+impl Default for Renderer {
+    /// Defaults to no color. `render` writes to an arbitrary `dyn Write`, which has
+    /// no way to ask "is this a TTY?", so guessing from some unrelated stream (e.g.
+    /// stdout) would color output even when the actual target isn't a terminal. Use
+    /// [`Renderer::for_target`] when you have the concrete destination in hand, or
+    /// [`Renderer::with_color`] to force it.
+    fn default() -> Self {
+        Self { color: false }
+    }
+}
+
+impl Renderer {
+    /// Equivalent to `Renderer::default()` — see its docs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Auto-detects color from whether `target` itself is a TTY, so rendering into
+    /// a file or in-memory buffer correctly disables color even when stdout is one.
+    pub fn for_target(target: &impl IsTerminal) -> Self {
+        Self { color: target.is_terminal() }
+    }
+
+    /// Overrides the color choice of `default()` (always off) or `for_target()`
+    /// (on only when the target is a TTY).
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn render(&self, log: &Log, w: &mut dyn Write) -> io::Result<()> {
+        if let Some(dt) = &log.dt {
+            write!(w, "{} ", dt)?;
+        }
+        let level = log.level_parsed();
+        let badge = level.map(|l| l.to_string().to_uppercase()).unwrap_or_else(|| "?????".to_string());
+        if self.color {
+            write!(w, "{}{:5}\x1b[0m ", ansi_color(level), badge)?;
+        } else {
+            write!(w, "{:5} ", badge)?;
+        }
+        if let Some(name) = &log.name {
+            write!(w, "{}: ", name)?;
+        }
+        write!(w, "{}", log.message)?;
+        let mut keys: Vec<&String> = log.data.keys().collect();
+        keys.sort();
+        for key in keys {
+            write!(w, " {}={}", key, log.data[key])?;
+        }
+        writeln!(w)
+    }
+}
+
+fn ansi_color(level: Option<Level>) -> &'static str {
+    match level {
+        Some(Level::Error) | Some(Level::Fatal) => "\x1b[31m",
+        Some(Level::Warn) => "\x1b[33m",
+        Some(Level::Info) => "\x1b[32m",
+        Some(Level::Debug) | Some(Level::Trace) => "\x1b[2m",
+        None => "",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{InnerStrategy, Parser, Strategy};
+
+    fn log(line: &str) -> Log {
+        Parser::new(Strategy::Direct(InnerStrategy::Logfmt)).parse(line.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let log = log("INFO server.onboarding: updated profile postal_code=10001 area=-");
+        let mut buf = Vec::new();
+        Renderer::new().with_color(false).render(&log, &mut buf).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(out, "INFO  server.onboarding: updated profile area=- postal_code=10001\n");
+    }
+
+    #[test]
+    fn test_default_does_not_assume_stdout() {
+        // Regression: `new()`/`default()` used to hardcode `io::stdout().is_terminal()`,
+        // so rendering into a non-TTY buffer under a TTY stdout would still emit color.
+        assert!(!Renderer::new().color);
+    }
+
+    #[test]
+    fn test_for_target_detects_non_tty() {
+        let file = std::fs::File::open("/dev/null").unwrap();
+        assert!(!Renderer::for_target(&file).color);
+    }
+
+    #[test]
+    fn test_render_color() {
+        let log = log("ERROR server.onboarding: failed");
+        let mut buf = Vec::new();
+        Renderer::new().with_color(true).render(&log, &mut buf).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert!(out.starts_with("\x1b[31m"));
+        assert!(out.contains("\x1b[0m"));
+    }
+}